@@ -2,32 +2,298 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use tauri::Manager;
-use std::process::{Command, Child};
-use std::sync::Mutex;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Child, Stdio};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use regex::Regex;
+use tokio::sync::oneshot;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 
-// State to track dev server process
+// A dev server running for a single repo, keyed by `repo_path` in
+// `DevServerState`. `generation` distinguishes this instance from whatever
+// previous or subsequent server occupies the same `repo_path` key, so a
+// watcher spawned for one generation can tell it's been superseded instead
+// of silently starting to track a different child.
+struct ManagedServer {
+    child: Child,
+    port: u16,
+    package_manager: &'static str,
+    generation: u64,
+}
+
+// Source of `ManagedServer::generation` values, unique across the app's
+// lifetime regardless of repo.
+static SERVER_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// State to track every dev server the app has started, one per repo, plus
+// any config-file watchers set up to auto-restart them.
 struct DevServerState {
-    process: Mutex<Option<Child>>,
+    servers: Mutex<HashMap<String, ManagedServer>>,
+    watchers: Mutex<HashMap<String, RecommendedWatcher>>,
+    // Serializes `spawn_and_register_dev_server` per repo_path so a manual
+    // start and a config-watcher restart (or two manual starts) for the same
+    // repo can't both pass the "kill previous" check and end up racing two
+    // live children for one map slot.
+    start_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+// Returns the lock guarding `spawn_and_register_dev_server` for `repo_path`,
+// creating it on first use.
+fn start_lock(state: &DevServerState, repo_path: &str) -> Arc<tokio::sync::Mutex<()>> {
+    state
+        .start_locks
+        .lock()
+        .unwrap()
+        .entry(repo_path.to_string())
+        .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(())))
+        .clone()
+}
+
+// Payload for the `dev-server-exited` event, fired when a managed child
+// terminates on its own, whether cleanly or not. A server that is removed from
+// state before it is killed (by `stop_dev_server`, a restart or app shutdown)
+// gets no event.
+#[derive(Clone, serde::Serialize)]
+struct DevServerExitedPayload {
+    repo_path: String,
+    code: Option<i32>,
+    signal: Option<i32>,
+}
+
+// How often the exit-watcher polls the child for termination.
+const EXIT_WATCH_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+#[cfg(unix)]
+fn exit_signal(status: &std::process::ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn exit_signal(_status: &std::process::ExitStatus) -> Option<i32> {
+    None
+}
+
+// Kills a managed child along with every process it transitively spawned.
+// On Unix the child runs in its own process group (see
+// `spawn_and_register_dev_server`),
+// so signalling the negative pid reaches the whole tree; elsewhere we fall
+// back to killing just the direct child.
+fn kill_child_tree(child: &mut Child) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        let pgid = child.id() as i32;
+        if unsafe { libc::kill(-pgid, libc::SIGKILL) } != 0 {
+            let err = std::io::Error::last_os_error();
+            // ESRCH means the process (group) is already gone — e.g. it
+            // crashed moments before this call and the exit watcher hasn't
+            // caught up yet. The caller wanted it not running, and it
+            // already isn't, so that's success, not a failure to report.
+            if err.raw_os_error() != Some(libc::ESRCH) {
+                return Err(err);
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        if let Err(e) = child.kill() {
+            // Returned when the process already exited — the same
+            // already-in-the-wanted-state case as ESRCH above.
+            if e.kind() != std::io::ErrorKind::InvalidInput {
+                return Err(e);
+            }
+        }
+    }
+
+    let _ = child.wait();
+    Ok(())
+}
+
+// Payload for the `dev-server-log` event, one per line of captured output.
+#[derive(Clone, serde::Serialize)]
+struct DevServerLogPayload {
+    repo_path: String,
+    stream: &'static str,
+    line: String,
+    ts: u64,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// How long we'll wait for the dev server to announce the port it bound before
+// giving up and surfacing an error to the frontend.
+const PORT_DETECTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Once a port is known, how long we'll poll it for readiness and how often.
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const READINESS_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Env var letting servers that never bind a port (e.g. a static file watcher)
+// opt out of the readiness check entirely.
+const SKIP_READINESS_ENV_VAR: &str = "ARTIK_SKIP_DEVSERVER_CHECK";
+
+// Patterns covering the common ways dev servers print their listen address:
+// Vite/webpack-style full URLs, Next.js' "Local:" line, and the generic
+// "ready on port N" phrasing some custom servers use.
+fn port_patterns() -> Vec<Regex> {
+    vec![
+        Regex::new(r"https?://(?:localhost|127\.0\.0\.1|0\.0\.0\.0):(\d+)").unwrap(),
+        Regex::new(r"(?i)local:\s*https?://[^\s:]+:(\d+)").unwrap(),
+        Regex::new(r"(?i)ready on port (\d+)").unwrap(),
+    ]
+}
+
+fn extract_port(line: &str, patterns: &[Regex]) -> Option<u16> {
+    patterns.iter().find_map(|re| {
+        re.captures(line)
+            .and_then(|caps| caps.get(1))
+            .and_then(|m| m.as_str().parse().ok())
+    })
+}
+
+// Drains a child's output stream on a dedicated thread, reporting the first
+// detected port through `port_tx` and forwarding every line to the frontend
+// as a `dev-server-log` event. The thread keeps running for the child's
+// lifetime so the pipe buffer never fills up and blocks it.
+fn spawn_log_reader<R>(
+    reader: R,
+    port_tx: Arc<Mutex<Option<oneshot::Sender<u16>>>>,
+    app_handle: tauri::AppHandle,
+    repo_path: String,
+    stream_name: &'static str,
+) where
+    R: std::io::Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let patterns = port_patterns();
+        for line in BufReader::new(reader).lines().flatten() {
+            if let Some(port) = extract_port(&line, &patterns) {
+                if let Some(tx) = port_tx.lock().unwrap().take() {
+                    let _ = tx.send(port);
+                }
+            }
+
+            let _ = app_handle.emit_all(
+                "dev-server-log",
+                DevServerLogPayload {
+                    repo_path: repo_path.clone(),
+                    stream: stream_name,
+                    line,
+                    ts: now_millis(),
+                },
+            );
+        }
+    });
+}
+
+// Polls the managed child tagged `generation` for `repo_path` until it
+// terminates, then emits `dev-server-exited` with its exit status and
+// removes it from the server map so callers can tell it's no longer running
+// instead of assuming it's still up. Checking `generation` rather than just
+// looking the entry up by `repo_path` means a watcher never mistakes a
+// replacement server (from a restart) for the one it was spawned to watch.
+fn spawn_exit_watcher(app_handle: tauri::AppHandle, repo_path: String, generation: u64) {
+    thread::spawn(move || loop {
+        thread::sleep(EXIT_WATCH_POLL_INTERVAL);
+
+        let state = app_handle.state::<DevServerState>();
+        let mut servers = state.servers.lock().unwrap();
+
+        let status = {
+            let server = match servers.get_mut(&repo_path) {
+                Some(server) if server.generation == generation => server,
+                // Stopped manually, or superseded by a newer server for the
+                // same repo_path — either way, this watcher's child is gone.
+                _ => break,
+            };
+
+            match server.child.try_wait() {
+                Ok(Some(status)) => status,
+                Ok(None) => continue,
+                Err(_) => break,
+            }
+        };
+
+        servers.remove(&repo_path);
+        drop(servers);
+
+        let _ = app_handle.emit_all(
+            "dev-server-exited",
+            DevServerExitedPayload {
+                repo_path,
+                code: status.code(),
+                signal: exit_signal(&status),
+            },
+        );
+        break;
+    });
 }
 
 #[tauri::command]
 async fn open_folder_dialog(app_handle: tauri::AppHandle) -> Result<String, String> {
     use tauri::api::dialog::blocking::FileDialogBuilder;
-    
+
     let folder = FileDialogBuilder::new()
         .pick_folder();
-    
+
     match folder {
         Some(path) => Ok(path.to_string_lossy().to_string()),
         None => Err("No folder selected".to_string()),
     }
 }
 
-#[tauri::command]
-async fn start_dev_server(
+// Polls `127.0.0.1:<port>` with short-interval TCP connects until the server
+// accepts a connection or `timeout` elapses.
+async fn wait_for_port_ready(port: u16, timeout: Duration) -> Result<(), String> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if tokio::net::TcpStream::connect(("127.0.0.1", port)).await.is_ok() {
+            return Ok(());
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return Err(format!(
+                "Dev server on port {} did not become reachable within {}s",
+                port,
+                timeout.as_secs()
+            ));
+        }
+
+        tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+    }
+}
+
+// Spawns the dev server for `repo_path`, wires up its log/port/exit
+// plumbing, and registers it in `DevServerState`. Shared by `start_dev_server`
+// and the config-file watcher's auto-restart.
+async fn spawn_and_register_dev_server(
     repo_path: String,
-    state: tauri::State<'_, DevServerState>,
+    app_handle: tauri::AppHandle,
 ) -> Result<u16, String> {
+    let state = app_handle.state::<DevServerState>();
+
+    // Hold the per-repo lock for the whole kill-spawn-detect-insert sequence
+    // so a second start/restart for the same repo_path waits for this one to
+    // finish instead of racing it past the "kill previous" check.
+    let lock = start_lock(&state, &repo_path);
+    let _guard = lock.lock().await;
+
+    // Kill any previous server for this repo before spawning its
+    // replacement, rather than after: starting the new child first would let
+    // both processes race for the same port for as long as detection takes.
+    if let Some(mut previous) = state.servers.lock().unwrap().remove(&repo_path) {
+        let _ = kill_child_tree(&mut previous.child);
+    }
+
     // Detect package manager and start dev server
     let pm = if std::path::Path::new(&format!("{}/pnpm-lock.yaml", repo_path)).exists() {
         "pnpm"
@@ -37,42 +303,307 @@ async fn start_dev_server(
         "npm"
     };
 
-    let child = Command::new(pm)
+    let mut command = Command::new(pm);
+    command
         .args(&["run", "dev"])
         .current_dir(&repo_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Prevent the package manager from popping up a console window; the app
+    // itself runs with `windows_subsystem = "windows"` so its children should
+    // stay hidden too.
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    // Put the child in its own process group so the whole tree it spawns
+    // (bundlers, worker processes, ...) can be signalled together on
+    // shutdown, not just the direct npm/pnpm/yarn wrapper.
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let mut child = command
         .spawn()
         .map_err(|e| format!("Failed to start dev server: {}", e))?;
 
-    let mut process_lock = state.process.lock().unwrap();
-    *process_lock = Some(child);
+    let stdout = child.stdout.take().expect("dev server stdout was piped");
+    let stderr = child.stderr.take().expect("dev server stderr was piped");
+
+    let (port_tx, port_rx) = oneshot::channel();
+    let port_tx = Arc::new(Mutex::new(Some(port_tx)));
+    spawn_log_reader(stdout, Arc::clone(&port_tx), app_handle.clone(), repo_path.clone(), "stdout");
+    spawn_log_reader(stderr, port_tx, app_handle.clone(), repo_path.clone(), "stderr");
+
+    let port = match tokio::time::timeout(PORT_DETECTION_TIMEOUT, port_rx).await {
+        Ok(Ok(port)) => port,
+        Ok(Err(_)) => {
+            let _ = kill_child_tree(&mut child);
+            return Err("Dev server exited before reporting a port".to_string());
+        }
+        Err(_) => {
+            // Detection failed, but the process is still running (e.g. a
+            // slow-starting server, or one whose output never matches any of
+            // our patterns) — kill it so it isn't orphaned outside of
+            // `state.servers`, where nothing would ever reap it.
+            let _ = kill_child_tree(&mut child);
+            return Err(format!(
+                "Timed out after {}s waiting for dev server to report a port",
+                PORT_DETECTION_TIMEOUT.as_secs()
+            ));
+        }
+    };
+
+    let generation = SERVER_GENERATION.fetch_add(1, Ordering::SeqCst);
+    state.servers.lock().unwrap().insert(
+        repo_path.clone(),
+        ManagedServer {
+            child,
+            port,
+            package_manager: pm,
+            generation,
+        },
+    );
 
-    // For M0, assume port 3001 (hardcoded)
-    // In production, parse output to detect actual port
-    Ok(3001)
+    spawn_exit_watcher(app_handle, repo_path, generation);
+
+    Ok(port)
 }
 
 #[tauri::command]
-async fn stop_dev_server(state: tauri::State<'_, DevServerState>) -> Result<(), String> {
-    let mut process_lock = state.process.lock().unwrap();
-    
-    if let Some(mut child) = process_lock.take() {
-        child.kill().map_err(|e| format!("Failed to stop dev server: {}", e))?;
+async fn start_dev_server(
+    repo_path: String,
+    skip_readiness_check: Option<bool>,
+    app_handle: tauri::AppHandle,
+) -> Result<u16, String> {
+    let port = spawn_and_register_dev_server(repo_path, app_handle).await?;
+
+    let skip_readiness_check = skip_readiness_check.unwrap_or(false)
+        || std::env::var(SKIP_READINESS_ENV_VAR).is_ok();
+
+    if !skip_readiness_check {
+        wait_for_port_ready(port, READINESS_TIMEOUT).await?;
+    }
+
+    Ok(port)
+}
+
+// Payload for the `dev-server-restarted` event, fired after a config-change
+// triggered restart has re-detected the (possibly new) port.
+#[derive(Clone, serde::Serialize)]
+struct DevServerRestartedPayload {
+    repo_path: String,
+    port: u16,
+}
+
+async fn restart_dev_server(repo_path: String, app_handle: tauri::AppHandle) {
+    let port = match spawn_and_register_dev_server(repo_path.clone(), app_handle.clone()).await {
+        Ok(port) => port,
+        Err(e) => {
+            eprintln!("Failed to restart dev server for {}: {}", repo_path, e);
+            return;
+        }
+    };
+
+    // Same readiness gate `start_dev_server` applies before handing a port
+    // to the frontend — otherwise the restart event can fire before the new
+    // process is actually accepting connections.
+    if std::env::var(SKIP_READINESS_ENV_VAR).is_err() {
+        if let Err(e) = wait_for_port_ready(port, READINESS_TIMEOUT).await {
+            eprintln!("Dev server for {} restarted but never became reachable: {}", repo_path, e);
+            return;
+        }
     }
-    
+
+    let _ = app_handle.emit_all(
+        "dev-server-restarted",
+        DevServerRestartedPayload { repo_path, port },
+    );
+}
+
+// Config files that should trigger a restart when they change. Matched by
+// file name rather than full glob so e.g. `vite.config.ts` and
+// `vite.config.mjs` are both covered.
+fn is_watched_config_file(path: &std::path::Path) -> bool {
+    let name = match path.file_name().and_then(|n| n.to_str()) {
+        Some(name) => name,
+        None => return false,
+    };
+
+    name == "package.json"
+        || name == ".env"
+        || name.starts_with("vite.config.")
+        || name.starts_with("next.config.")
+}
+
+// Debounce window for collapsing a burst of file-system events (editors and
+// formatters often touch a file more than once) into a single restart.
+const RESTART_DEBOUNCE: Duration = Duration::from_millis(300);
+// Loop guard: the minimum gap between restarts, so a flapping config file
+// can't trigger a restart storm.
+const RESTART_MIN_INTERVAL: Duration = Duration::from_secs(2);
+
+// Watches `repo_path` for changes to its dev-server config files and
+// schedules a restart through the Tauri async runtime whenever one changes.
+fn start_config_watcher(
+    repo_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<RecommendedWatcher, String> {
+    let (tx, rx) = std::sync::mpsc::channel::<notify::Event>();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })
+    .map_err(|e| format!("Failed to create watcher for {}: {}", repo_path, e))?;
+
+    watcher
+        .watch(std::path::Path::new(&repo_path), RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch {}: {}", repo_path, e))?;
+
+    thread::spawn(move || {
+        let mut last_restart = std::time::Instant::now() - RESTART_MIN_INTERVAL;
+
+        // The loop (and thread) ends once `enable_watch(false)` drops the
+        // watcher, which drops the closure above and closes `tx`.
+        while let Ok(event) = rx.recv() {
+            if !event.paths.iter().any(|p| is_watched_config_file(p)) {
+                continue;
+            }
+
+            while rx.recv_timeout(RESTART_DEBOUNCE).is_ok() {}
+
+            // Loop guard: never restart more often than `RESTART_MIN_INTERVAL`,
+            // but defer a restart that lands inside the window rather than
+            // drop it, so an edit shortly after a restart isn't lost.
+            let remaining = RESTART_MIN_INTERVAL.saturating_sub(last_restart.elapsed());
+            if !remaining.is_zero() {
+                thread::sleep(remaining);
+                // Fold in anything that arrived while we waited instead of
+                // queuing it up as a second restart right behind this one.
+                while rx.try_recv().is_ok() {}
+            }
+
+            last_restart = std::time::Instant::now();
+
+            tauri::async_runtime::spawn(restart_dev_server(repo_path.clone(), app_handle.clone()));
+        }
+    });
+
+    Ok(watcher)
+}
+
+#[tauri::command]
+async fn enable_watch(
+    repo_path: String,
+    enabled: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let state = app_handle.state::<DevServerState>();
+
+    if enabled {
+        let watcher = start_config_watcher(repo_path.clone(), app_handle)?;
+        state.watchers.lock().unwrap().insert(repo_path, watcher);
+    } else {
+        state.watchers.lock().unwrap().remove(&repo_path);
+    }
+
     Ok(())
 }
 
+#[tauri::command]
+async fn stop_dev_server(
+    repo_path: Option<String>,
+    state: tauri::State<'_, DevServerState>,
+) -> Result<(), String> {
+    let mut servers = state.servers.lock().unwrap();
+
+    match repo_path {
+        Some(repo_path) => {
+            if let Some(mut server) = servers.remove(&repo_path) {
+                kill_child_tree(&mut server.child).map_err(|e| {
+                    format!("Failed to stop {} dev server for {}: {}", server.package_manager, repo_path, e)
+                })?;
+            }
+        }
+        None => {
+            let mut first_err = None;
+            for (repo_path, mut server) in servers.drain() {
+                if let Err(e) = kill_child_tree(&mut server.child) {
+                    first_err.get_or_insert_with(|| {
+                        format!("Failed to stop {} dev server for {}: {}", server.package_manager, repo_path, e)
+                    });
+                }
+            }
+            if let Some(err) = first_err {
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, serde::Serialize)]
+struct DevServerInfo {
+    repo_path: String,
+    port: u16,
+}
+
+#[tauri::command]
+async fn list_dev_servers(state: tauri::State<'_, DevServerState>) -> Result<Vec<DevServerInfo>, String> {
+    let servers = state.servers.lock().unwrap();
+    Ok(servers
+        .iter()
+        .map(|(repo_path, server)| DevServerInfo {
+            repo_path: repo_path.clone(),
+            port: server.port,
+        })
+        .collect())
+}
+
+// Kills every dev server still tracked in state. Called when the app is
+// shutting down so no `npm run dev` (or its children) is left orphaned.
+fn kill_all_dev_servers(app_handle: &tauri::AppHandle) {
+    let state = app_handle.state::<DevServerState>();
+    let mut servers = state.servers.lock().unwrap();
+    for (repo_path, mut server) in servers.drain() {
+        if let Err(e) = kill_child_tree(&mut server.child) {
+            eprintln!("Failed to stop dev server for {} during shutdown: {}", repo_path, e);
+        }
+    }
+}
+
 fn main() {
-    tauri::Builder::default()
+    let app = tauri::Builder::default()
         .manage(DevServerState {
-            process: Mutex::new(None),
+            servers: Mutex::new(HashMap::new()),
+            watchers: Mutex::new(HashMap::new()),
+            start_locks: Mutex::new(HashMap::new()),
         })
         .invoke_handler(tauri::generate_handler![
             open_folder_dialog,
             start_dev_server,
-            stop_dev_server
+            stop_dev_server,
+            list_dev_servers,
+            enable_watch
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
-}
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application");
 
+    app.run(|app_handle, event| match event {
+        tauri::RunEvent::Exit => kill_all_dev_servers(app_handle),
+        tauri::RunEvent::WindowEvent {
+            event: tauri::WindowEvent::CloseRequested { .. },
+            ..
+        } => kill_all_dev_servers(app_handle),
+        _ => {}
+    });
+}